@@ -1,8 +1,32 @@
 //! Track mouse clicks.
 use crate::mouse::Button;
-use crate::time::Instant;
+use crate::time::{Duration, Instant};
 use crate::Point;
 
+use std::sync::atomic::{self, AtomicU32};
+
+static INTERVAL: AtomicU32 = AtomicU32::new(300);
+
+/// Returns the maximum interval between two consecutive clicks for them to
+/// be considered a double or triple click.
+///
+/// By default, this is 300 milliseconds.
+pub fn interval() -> Duration {
+    Duration::from_millis(u64::from(INTERVAL.load(atomic::Ordering::Relaxed)))
+}
+
+/// Sets the maximum interval between two consecutive clicks for them to be
+/// considered a double or triple click.
+///
+/// This applies to the whole application; a good value is normally the one
+/// configured by the user in their desktop environment.
+pub fn set_interval(interval: Duration) {
+    INTERVAL.store(
+        u32::try_from(interval.as_millis()).unwrap_or(u32::MAX),
+        atomic::Ordering::Relaxed,
+    );
+}
+
 /// A mouse click.
 #[derive(Debug, Clone, Copy)]
 pub struct Click {
@@ -84,7 +108,34 @@ impl Click {
 
         self.position == new_position
             && duration
-                .map(|duration| duration.as_millis() <= 300)
+                .map(|duration| duration <= interval())
                 .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+
+    #[test]
+    fn interval_limits_consecutive_clicks() {
+        set_interval(Duration::from_millis(100));
+
+        let position = Point::new(10.0, 10.0);
+
+        let first = Click::new(position, Button::Left, None);
+        thread::sleep(Duration::from_millis(5));
+
+        let second = Click::new(position, Button::Left, Some(first));
+        assert!(matches!(second.kind(), Kind::Double));
+
+        thread::sleep(Duration::from_millis(200));
+
+        let third = Click::new(position, Button::Left, Some(second));
+        assert!(matches!(third.kind(), Kind::Single));
+
+        set_interval(Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{Point, Size};
+
+/// A mouse cursor made of custom RGBA pixels in the `sRGB` color space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomCursor {
+    /// The RGBA pixels of the [`CustomCursor`].
+    pub rgba: Vec<u8>,
+
+    /// The size of the [`CustomCursor`], in pixels.
+    pub size: Size<u16>,
+
+    /// The pixel of the [`CustomCursor`] that points at the cursor position.
+    pub hotspot: Point<u16>,
+}
@@ -25,6 +25,19 @@ pub struct Count {
     pub total: usize,
 }
 
+/// The behavior of focus traversal when it reaches either end of the
+/// focusable widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edge {
+    /// The focused widget is unfocused, and traversal starts over from the
+    /// other end.
+    #[default]
+    Wrap,
+
+    /// The focus stays on the first or last focusable widget.
+    Clamp,
+}
+
 /// Produces an [`Operation`] that focuses the widget with the given [`Id`].
 pub fn focus<T>(target: Id) -> impl Operation<T> {
     struct Focus {
@@ -95,12 +108,22 @@ pub fn count() -> impl Operation<Count> {
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
 pub fn focus_previous<T>() -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    focus_previous_with(Edge::Wrap)
+}
+
+/// Produces an [`Operation`] like [`focus_previous`], but with the given
+/// [`Edge`] behavior once the first focusable widget is reached.
+pub fn focus_previous_with<T>(edge: Edge) -> impl Operation<T>
 where
     T: Send + 'static,
 {
     struct FocusPrevious {
         count: Count,
         current: usize,
+        edge: Edge,
     }
 
     impl<T> Operation<T> for FocusPrevious {
@@ -111,6 +134,7 @@ where
 
             match self.count.focused {
                 None if self.current == self.count.total - 1 => state.focus(),
+                Some(0) if self.edge == Edge::Clamp => {}
                 Some(0) if self.current == 0 => state.unfocus(),
                 Some(0) => {}
                 Some(focused) if focused == self.current => state.unfocus(),
@@ -131,25 +155,50 @@ where
         }
     }
 
-    operation::then(count(), |count| FocusPrevious { count, current: 0 })
+    // `then` takes a `fn` pointer, so the closures cannot capture `edge`
+    match edge {
+        Edge::Wrap => operation::then(count(), |count| FocusPrevious {
+            count,
+            current: 0,
+            edge: Edge::Wrap,
+        }),
+        Edge::Clamp => operation::then(count(), |count| FocusPrevious {
+            count,
+            current: 0,
+            edge: Edge::Clamp,
+        }),
+    }
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the next focusable widget.
 /// - if not found, focuses the first focusable widget.
 pub fn focus_next<T>() -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    focus_next_with(Edge::Wrap)
+}
+
+/// Produces an [`Operation`] like [`focus_next`], but with the given [`Edge`]
+/// behavior once the last focusable widget is reached.
+pub fn focus_next_with<T>(edge: Edge) -> impl Operation<T>
 where
     T: Send + 'static,
 {
     struct FocusNext {
         count: Count,
         current: usize,
+        edge: Edge,
     }
 
     impl<T> Operation<T> for FocusNext {
         fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
             match self.count.focused {
                 None if self.current == 0 => state.focus(),
+                Some(focused)
+                    if self.edge == Edge::Clamp
+                        && focused + 1 == self.count.total => {}
                 Some(focused) if focused == self.current => state.unfocus(),
                 Some(focused) if focused + 1 == self.current => state.focus(),
                 _ => {}
@@ -168,7 +217,19 @@ where
         }
     }
 
-    operation::then(count(), |count| FocusNext { count, current: 0 })
+    // `then` takes a `fn` pointer, so the closures cannot capture `edge`
+    match edge {
+        Edge::Wrap => operation::then(count(), |count| FocusNext {
+            count,
+            current: 0,
+            edge: Edge::Wrap,
+        }),
+        Edge::Clamp => operation::then(count(), |count| FocusNext {
+            count,
+            current: 0,
+            edge: Edge::Clamp,
+        }),
+    }
 }
 
 /// Produces an [`Operation`] that searches for the current focused widget
@@ -205,3 +266,87 @@ pub fn find_focused() -> impl Operation<Id> {
 
     FindFocused { focused: None }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct State(bool);
+
+    impl Focusable for State {
+        fn is_focused(&self) -> bool {
+            self.0
+        }
+
+        fn focus(&mut self) {
+            self.0 = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.0 = false;
+        }
+    }
+
+    fn run(states: &mut [State], operation: impl Operation<()> + 'static) {
+        let mut operation: Box<dyn Operation<()>> = Box::new(operation);
+
+        loop {
+            for state in states.iter_mut() {
+                operation.focusable(state, None);
+            }
+
+            match operation.finish() {
+                Outcome::Chain(next) => operation = next,
+                _ => break,
+            }
+        }
+    }
+
+    fn focused(states: &[State]) -> Option<usize> {
+        states.iter().position(|state| state.0)
+    }
+
+    #[test]
+    fn focus_next_wraps_around() {
+        let mut states = [State(false), State(false), State(true)];
+
+        run(&mut states, focus_next_with(Edge::Wrap));
+        assert_eq!(focused(&states), None);
+
+        run(&mut states, focus_next_with(Edge::Wrap));
+        assert_eq!(focused(&states), Some(0));
+    }
+
+    #[test]
+    fn focus_next_clamps() {
+        let mut states = [State(false), State(true), State(false)];
+
+        run(&mut states, focus_next_with(Edge::Clamp));
+        assert_eq!(focused(&states), Some(2));
+
+        run(&mut states, focus_next_with(Edge::Clamp));
+        assert_eq!(focused(&states), Some(2));
+    }
+
+    #[test]
+    fn focus_previous_wraps_around() {
+        let mut states = [State(true), State(false), State(false)];
+
+        run(&mut states, focus_previous_with(Edge::Wrap));
+        assert_eq!(focused(&states), None);
+
+        run(&mut states, focus_previous_with(Edge::Wrap));
+        assert_eq!(focused(&states), Some(2));
+    }
+
+    #[test]
+    fn focus_previous_clamps() {
+        let mut states = [State(false), State(true), State(false)];
+
+        run(&mut states, focus_previous_with(Edge::Clamp));
+        assert_eq!(focused(&states), Some(0));
+
+        run(&mut states, focus_previous_with(Edge::Clamp));
+        assert_eq!(focused(&states), Some(0));
+    }
+}
@@ -4,18 +4,22 @@ pub mod settings;
 
 mod event;
 mod id;
+mod input_state;
 mod level;
 mod mode;
 mod position;
 mod redraw_request;
+mod summary;
 mod user_attention;
 
 pub use event::Event;
 pub use icon::Icon;
 pub use id::Id;
+pub use input_state::InputState;
 pub use level::Level;
 pub use mode::Mode;
 pub use position::Position;
 pub use redraw_request::RedrawRequest;
 pub use settings::Settings;
+pub use summary::Summary;
 pub use user_attention::UserAttention;
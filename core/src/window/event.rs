@@ -23,6 +23,12 @@ pub enum Event {
     Closed,
 
     /// A window was moved.
+    ///
+    /// The [`Point`] contains the new logical position of the window, as
+    /// reported by the windowing system.
+    ///
+    /// **Note**: Not available in Wayland, since clients cannot know the
+    /// position of their windows.
     Moved(Point),
 
     /// A window was resized.
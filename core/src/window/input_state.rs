@@ -0,0 +1,12 @@
+use crate::keyboard;
+use crate::mouse;
+
+/// A snapshot of the input currently held down over a window.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InputState {
+    /// The keyboard modifiers currently pressed.
+    pub modifiers: keyboard::Modifiers,
+
+    /// The mouse buttons currently pressed over the window.
+    pub buttons: Vec<mouse::Button>,
+}
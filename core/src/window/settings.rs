@@ -73,6 +73,13 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// An application-defined tag attached to the window.
+    ///
+    /// It can be used to identify the purpose of a window (e.g. a preferences
+    /// window) and can be read back at any time with the `window::get_tag`
+    /// task.
+    pub tag: Option<String>,
 }
 
 impl Default for Settings {
@@ -89,6 +96,7 @@ impl Default for Settings {
             level: Level::default(),
             icon: None,
             exit_on_close_request: true,
+            tag: None,
             platform_specific: PlatformSpecific::default(),
         }
     }
@@ -0,0 +1,18 @@
+use crate::window::Id;
+use crate::Size;
+
+/// A brief description of an open window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// The [`Id`] of the window.
+    pub id: Id,
+
+    /// The current title of the window.
+    pub title: String,
+
+    /// The current logical size of the window.
+    pub size: Size,
+
+    /// Whether the window currently has keyboard focus.
+    pub is_focused: bool,
+}
@@ -119,6 +119,14 @@ impl Example {
                 let graphics_backend =
                     text!("Graphics backend: {}", information.graphics_backend);
 
+                let desktop_environment = text!(
+                    "Desktop environment: {}",
+                    information
+                        .desktop_environment
+                        .as_deref()
+                        .unwrap_or("Unknown")
+                );
+
                 column![
                     system_name.size(30),
                     system_kernel.size(30),
@@ -130,6 +138,7 @@ impl Example {
                     memory_used.size(30),
                     graphics_adapter.size(30),
                     graphics_backend.size(30),
+                    desktop_environment.size(30),
                     button("Refresh").on_press(Message::Refresh)
                 ]
                 .spacing(10)
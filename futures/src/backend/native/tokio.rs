@@ -24,6 +24,11 @@ pub mod time {
     //! Listen and react to time.
     use crate::subscription::{self, Hasher, Subscription};
 
+    /// Completes once the given `duration` has passed.
+    pub async fn sleep(duration: std::time::Duration) {
+        tokio::time::sleep(duration).await;
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
@@ -19,6 +19,11 @@ pub mod time {
     use crate::subscription::{self, Hasher, Subscription};
     use crate::BoxStream;
 
+    /// Completes once the given `duration` has passed.
+    pub async fn sleep(duration: std::time::Duration) {
+        let _ = wasm_timer::Delay::new(duration).await;
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
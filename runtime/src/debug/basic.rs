@@ -29,8 +29,13 @@ pub struct Debug {
     render_start: time::Instant,
     render_durations: TimeBuffer,
 
+    requested_frames: usize,
+    skipped_frames: usize,
+    presented_frames: usize,
+
     message_count: usize,
     last_messages: VecDeque<String>,
+    message_log: Option<MessageLog>,
 }
 
 impl Debug {
@@ -61,8 +66,13 @@ impl Debug {
             render_start: now,
             render_durations: TimeBuffer::new(50),
 
+            requested_frames: 0,
+            skipped_frames: 0,
+            presented_frames: 0,
+
             message_count: 0,
             last_messages: VecDeque::new(),
+            message_log: None,
         }
     }
 
@@ -126,8 +136,30 @@ impl Debug {
         self.render_durations.push(self.render_start.elapsed());
     }
 
+    pub fn frame_requested(&mut self) {
+        self.requested_frames += 1;
+    }
+
+    pub fn frame_skipped(&mut self) {
+        self.skipped_frames += 1;
+    }
+
+    pub fn frame_presented(&mut self) {
+        self.presented_frames += 1;
+    }
+
+    pub fn log_messages_with(&mut self, f: impl Fn(&str) + 'static) {
+        self.message_log = Some(MessageLog(Box::new(f)));
+    }
+
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
-        self.last_messages.push_back(format!("{message:?}"));
+        let message = format!("{message:?}");
+
+        if let Some(MessageLog(log)) = &self.message_log {
+            log(&message);
+        }
+
+        self.last_messages.push_back(message);
 
         if self.last_messages.len() > 10 {
             let _ = self.last_messages.pop_front();
@@ -166,6 +198,9 @@ impl Debug {
             self.draw_durations.average(),
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
+        lines.push(key_value("Frames requested:", self.requested_frames));
+        lines.push(key_value("Frames skipped:", self.skipped_frames));
+        lines.push(key_value("Frames presented:", self.presented_frames));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
@@ -186,6 +221,14 @@ impl Default for Debug {
     }
 }
 
+struct MessageLog(Box<dyn Fn(&str)>);
+
+impl std::fmt::Debug for MessageLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MessageLog(..)")
+    }
+}
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
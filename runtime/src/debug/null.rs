@@ -35,6 +35,12 @@ impl Debug {
 
     pub fn render_finished(&mut self) {}
 
+    pub fn frame_requested(&mut self) {}
+
+    pub fn frame_skipped(&mut self) {}
+
+    pub fn frame_presented(&mut self) {}
+
     pub fn log_message<Message: std::fmt::Debug>(
         &mut self,
         _message: &Message,
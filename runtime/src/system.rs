@@ -6,6 +6,9 @@ use crate::futures::futures::channel::oneshot;
 pub enum Action {
     /// Query system information and produce `T` with the result.
     QueryInformation(oneshot::Sender<Information>),
+
+    /// Query the names of the font families known to the renderer.
+    QueryFonts(oneshot::Sender<Vec<String>>),
 }
 
 /// Contains informations about the system (e.g. system name, processor, memory, graphics adapter).
@@ -36,4 +39,9 @@ pub struct Information {
     pub graphics_backend: String,
     /// Model information for the active graphics adapter
     pub graphics_adapter: String,
+    /// The name of the desktop environment or compositor, if known
+    ///
+    /// This is a heuristic based on the environment of the process (e.g.
+    /// `XDG_CURRENT_DESKTOP` on Linux) and may be `None` or imprecise.
+    pub desktop_environment: Option<String>,
 }
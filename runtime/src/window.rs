@@ -3,11 +3,12 @@ pub mod screenshot;
 
 pub use screenshot::Screenshot;
 
-use crate::core::time::Instant;
+use crate::core::mouse;
+use crate::core::time::{Duration, Instant};
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    Event, Icon, Id, InputState, Level, Mode, Settings, Summary, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Color, Point, Size};
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::Subscription;
@@ -32,6 +33,9 @@ pub enum Action {
     /// Gets the [`Id`] of the latest window.
     GetLatest(oneshot::Sender<Option<Id>>),
 
+    /// Gets a [`Summary`] of every open window, from oldest to latest.
+    GetAll(oneshot::Sender<Vec<Summary>>),
+
     /// Move the window with the left mouse button until the button is
     /// released.
     ///
@@ -66,14 +70,43 @@ pub enum Action {
     /// Get the current scale factor (DPI) of the window.
     GetScaleFactor(Id, oneshot::Sender<f32>),
 
+    /// Get the last known logical coordinates of the cursor over the window,
+    /// if it is over it.
+    GetCursorPosition(Id, oneshot::Sender<Option<Point>>),
+
+    /// Get the [`InputState`] of the window.
+    GetInputState(Id, oneshot::Sender<InputState>),
+
     /// Move the window to the given logical coordinates.
     ///
     /// Unsupported on Wayland.
     Move(Id, Point),
 
+    /// Hide the cursor of the window after it has not moved over it for
+    /// the given [`Duration`]; `None` disables it.
+    ///
+    /// The cursor is shown again as soon as it moves.
+    AutoHideCursor(Id, Option<Duration>),
+
+    /// Change the cursor of the window to the given [`mouse::CustomCursor`];
+    /// `None` restores the standard cursors.
+    ChangeCustomCursor(Id, Option<mouse::CustomCursor>),
+
+    /// Move the window to the center of its current monitor.
+    ///
+    /// Unsupported on Wayland.
+    Center(Id),
+
     /// Change the [`Mode`] of the window.
     ChangeMode(Id, Mode),
 
+    /// Change the clear [`Color`] of the window, overriding the background
+    /// color of its theme; `None` restores the theme background.
+    ///
+    /// A transparent clear color only shows through if the window was
+    /// created with [`Settings::transparent`] enabled.
+    ChangeClearColor(Id, Option<Color>),
+
     /// Get the current [`Mode`] of the window.
     GetMode(Id, oneshot::Sender<Mode>),
 
@@ -126,6 +159,9 @@ pub enum Action {
     /// Get the raw identifier unique to the window.
     GetRawId(Id, oneshot::Sender<u64>),
 
+    /// Get the tag of the window, as set in its [`Settings`].
+    GetTag(Id, oneshot::Sender<Option<String>>),
+
     /// Change the window [`Icon`].
     ///
     /// On Windows and X11, this is typically the small icon in the top-left
@@ -259,6 +295,11 @@ pub fn get_latest() -> Task<Option<Id>> {
     task::oneshot(|channel| crate::Action::Window(Action::GetLatest(channel)))
 }
 
+/// Gets a [`Summary`] of every open window, from oldest to latest.
+pub fn get_all() -> Task<Vec<Summary>> {
+    task::oneshot(|channel| crate::Action::Window(Action::GetAll(channel)))
+}
+
 /// Begins dragging the window while the left mouse button is held.
 pub fn drag<T>(id: Id) -> Task<T> {
     task::effect(crate::Action::Window(Action::Drag(id)))
@@ -314,16 +355,70 @@ pub fn get_scale_factor(id: Id) -> Task<f32> {
     })
 }
 
+/// Gets the last known logical coordinates of the cursor over the window.
+///
+/// Produces `None` if the cursor is not over the window.
+pub fn get_cursor_position(id: Id) -> Task<Option<Point>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetCursorPosition(id, channel))
+    })
+}
+
+/// Gets the [`InputState`] of the window; that is, the keyboard modifiers
+/// and mouse buttons currently held down over it.
+pub fn get_input_state(id: Id) -> Task<InputState> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetInputState(id, channel))
+    })
+}
+
 /// Moves the window to the given logical coordinates.
 pub fn move_to<T>(id: Id, position: Point) -> Task<T> {
     task::effect(crate::Action::Window(Action::Move(id, position)))
 }
 
+/// Hides the cursor of the window after it has been idle over it for the
+/// given [`Duration`].
+///
+/// Passing `None` disables the auto-hiding.
+pub fn auto_hide_cursor<T>(id: Id, after: Option<Duration>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::AutoHideCursor(id, after)))
+}
+
+/// Changes the cursor of the window to the given [`mouse::CustomCursor`].
+///
+/// The cursor is kept while it is over the window, regardless of the
+/// [`mouse::Interaction`] of its widgets. Passing `None` restores the
+/// standard cursors.
+pub fn change_custom_cursor<T>(
+    id: Id,
+    cursor: Option<mouse::CustomCursor>,
+) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ChangeCustomCursor(
+        id, cursor,
+    )))
+}
+
+/// Moves the window to the center of its current monitor.
+pub fn center<T>(id: Id) -> Task<T> {
+    task::effect(crate::Action::Window(Action::Center(id)))
+}
+
 /// Changes the [`Mode`] of the window.
 pub fn change_mode<T>(id: Id, mode: Mode) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeMode(id, mode)))
 }
 
+/// Changes the clear [`Color`] of the window, overriding the background
+/// color of its theme.
+///
+/// Passing `None` restores the background color of the theme. A transparent
+/// clear color only shows through if the window was created with
+/// [`Settings::transparent`] enabled.
+pub fn change_clear_color<T>(id: Id, color: Option<Color>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ChangeClearColor(id, color)))
+}
+
 /// Gets the current [`Mode`] of the window.
 pub fn get_mode(id: Id) -> Task<Mode> {
     task::oneshot(move |channel| {
@@ -387,6 +482,14 @@ pub fn get_raw_id<Message>(id: Id) -> Task<u64> {
     })
 }
 
+/// Gets the tag of the window with the given [`Id`], as set in its
+/// [`Settings::tag`].
+pub fn get_tag(id: Id) -> Task<Option<String>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetTag(id, channel))
+    })
+}
+
 /// Changes the [`Icon`] of the window.
 pub fn change_icon<T>(id: Id, icon: Icon) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeIcon(id, icon)))
@@ -31,6 +31,7 @@
 //! }
 //! ```
 use crate::program::{self, Program};
+use crate::time::Duration;
 use crate::window;
 use crate::{
     Element, Executor, Font, Result, Settings, Size, Subscription, Task,
@@ -182,6 +183,51 @@ impl<P: Program> Application<P> {
         Self { settings, ..self }
     }
 
+    /// Sets the [`Settings::id`] of the [`Application`].
+    ///
+    /// On Linux, this identifier is used as the application id of its windows
+    /// unless one is explicitly set in their platform-specific settings.
+    pub fn id(self, id: impl Into<String>) -> Self {
+        Self {
+            settings: Settings {
+                id: Some(id.into()),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the function that will receive the [`Debug`] representation of
+    /// every message processed by the [`Application`].
+    ///
+    /// See [`Settings::message_log`].
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    #[cfg(feature = "debug")]
+    pub fn log_messages(
+        self,
+        f: impl Fn(&str) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            settings: Settings {
+                message_log: Some(crate::settings::MessageLog::new(f)),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::double_click_interval`] of the [`Application`].
+    pub fn double_click_interval(self, interval: Duration) -> Self {
+        Self {
+            settings: Settings {
+                double_click_interval: Some(interval),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`Settings::antialiasing`] of the [`Application`].
     pub fn antialiasing(self, antialiasing: bool) -> Self {
         Self {
@@ -379,6 +425,25 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the message produced when the window of the [`Application`] is opened.
+    ///
+    /// The closure receives the [`window::Id`] of the window and its
+    /// initial logical [`Size`], as reported by the windowing system.
+    pub fn on_open(
+        self,
+        f: impl Fn(window::Id, Size) -> P::Message,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Application {
+            raw: program::with_opened(self.raw, move |_state, window, size| {
+                Some(f(window, size))
+            }),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
     /// Sets the executor of the [`Application`].
     pub fn executor<E>(
         self,
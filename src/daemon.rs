@@ -1,8 +1,11 @@
 //! Create and run daemons that run in the background.
 use crate::application;
 use crate::program::{self, Program};
+use crate::time::Duration;
 use crate::window;
-use crate::{Element, Executor, Font, Result, Settings, Subscription, Task};
+use crate::{
+    Element, Executor, Font, Result, Settings, Size, Subscription, Task,
+};
 
 use std::borrow::Cow;
 
@@ -129,6 +132,51 @@ impl<P: Program> Daemon<P> {
         Self { settings, ..self }
     }
 
+    /// Sets the [`Settings::id`] of the [`Daemon`].
+    ///
+    /// On Linux, this identifier is used as the application id of its windows
+    /// unless one is explicitly set in their platform-specific settings.
+    pub fn id(self, id: impl Into<String>) -> Self {
+        Self {
+            settings: Settings {
+                id: Some(id.into()),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the function that will receive the [`Debug`] representation of
+    /// every message processed by the [`Daemon`].
+    ///
+    /// See [`Settings::message_log`].
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    #[cfg(feature = "debug")]
+    pub fn log_messages(
+        self,
+        f: impl Fn(&str) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            settings: Settings {
+                message_log: Some(crate::settings::MessageLog::new(f)),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::double_click_interval`] of the [`Daemon`].
+    pub fn double_click_interval(self, interval: Duration) -> Self {
+        Self {
+            settings: Settings {
+                double_click_interval: Some(interval),
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`Settings::antialiasing`] of the [`Daemon`].
     pub fn antialiasing(self, antialiasing: bool) -> Self {
         Self {
@@ -224,6 +272,24 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the message produced when a window of the [`Daemon`] is opened.
+    ///
+    /// The closure receives the [`window::Id`] of the new window and its
+    /// initial logical [`Size`], as reported by the windowing system.
+    pub fn on_open(
+        self,
+        f: impl Fn(window::Id, Size) -> P::Message,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Daemon {
+            raw: program::with_opened(self.raw, move |_state, window, size| {
+                Some(f(window, size))
+            }),
+            settings: self.settings,
+        }
+    }
+
     /// Sets the executor of the [`Daemon`].
     pub fn executor<E>(
         self,
@@ -569,8 +569,9 @@ pub mod keyboard {
 
 pub mod mouse {
     //! Listen and react to mouse events.
+    pub use crate::core::mouse::click;
     pub use crate::core::mouse::{
-        Button, Cursor, Event, Interaction, ScrollDelta,
+        Button, Cursor, CustomCursor, Event, Interaction, ScrollDelta,
     };
 }
 
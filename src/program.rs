@@ -2,7 +2,7 @@ use crate::core::text;
 use crate::graphics::compositor;
 use crate::shell;
 use crate::window;
-use crate::{Element, Executor, Result, Settings, Subscription, Task};
+use crate::{Element, Executor, Result, Settings, Size, Subscription, Task};
 
 pub use crate::shell::program::{Appearance, DefaultStyle};
 
@@ -62,6 +62,15 @@ pub trait Program: Sized {
         1.0
     }
 
+    fn opened(
+        &self,
+        _state: &Self::State,
+        _window: window::Id,
+        _size: Size,
+    ) -> Option<Self::Message> {
+        None
+    }
+
     /// Runs the [`Program`].
     ///
     /// The state of the [`Program`] must implement [`Default`].
@@ -160,6 +169,14 @@ pub trait Program: Sized {
             fn scale_factor(&self, window: window::Id) -> f64 {
                 self.program.scale_factor(&self.state, window)
             }
+
+            fn opened(
+                &self,
+                window: window::Id,
+                size: Size,
+            ) -> Option<Self::Message> {
+                self.program.opened(&self.state, window, size)
+            }
         }
 
         #[allow(clippy::needless_update)]
@@ -184,6 +201,10 @@ pub trait Program: Sized {
                 default_font: settings.default_font,
                 default_text_size: settings.default_text_size,
                 antialiasing: settings.antialiasing,
+                mouse_emulation: settings.mouse_emulation,
+                double_click_interval: settings.double_click_interval,
+                #[cfg(feature = "debug")]
+                message_log: settings.message_log,
             }
             .into(),
             renderer_settings,
@@ -259,6 +280,15 @@ pub fn with_title<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            self.program.opened(state, window, size)
+        }
     }
 
     WithTitle { program, title }
@@ -329,6 +359,15 @@ pub fn with_subscription<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            self.program.opened(state, window, size)
+        }
     }
 
     WithSubscription {
@@ -402,6 +441,15 @@ pub fn with_theme<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            self.program.opened(state, window, size)
+        }
     }
 
     WithTheme { program, theme: f }
@@ -472,6 +520,15 @@ pub fn with_style<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            self.program.opened(state, window, size)
+        }
     }
 
     WithStyle { program, style: f }
@@ -542,6 +599,15 @@ pub fn with_scale_factor<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             (self.scale_factor)(state, window)
         }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            self.program.opened(state, window, size)
+        }
     }
 
     WithScaleFactor {
@@ -550,6 +616,85 @@ pub fn with_scale_factor<P: Program>(
     }
 }
 
+pub fn with_opened<P: Program>(
+    program: P,
+    f: impl Fn(&P::State, window::Id, Size) -> Option<P::Message>,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithOpened<P, F> {
+        program: P,
+        opened: F,
+    }
+
+    impl<P: Program, F> Program for WithOpened<P, F>
+    where
+        F: Fn(&P::State, window::Id, Size) -> Option<P::Message>,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            (self.opened)(state, window, size)
+        }
+    }
+
+    WithOpened { program, opened: f }
+}
+
 pub fn with_executor<P: Program, E: Executor>(
     program: P,
 ) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
@@ -616,6 +761,15 @@ pub fn with_executor<P: Program, E: Executor>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn opened(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+            size: Size,
+        ) -> Option<Self::Message> {
+            self.program.opened(state, window, size)
+        }
     }
 
     WithExecutor {
@@ -1,8 +1,12 @@
 //! Configure your application.
+use crate::time::Duration;
 use crate::{Font, Pixels};
 
 use std::borrow::Cow;
 
+#[cfg(feature = "debug")]
+pub use iced_winit::settings::MessageLog;
+
 /// The settings of an iced program.
 #[derive(Debug, Clone)]
 pub struct Settings {
@@ -35,6 +39,42 @@ pub struct Settings {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
+
+    /// If set to true, single-finger touch interactions that are ignored
+    /// by the widgets of a window will be emulated as left mouse button
+    /// presses, movements, and releases.
+    ///
+    /// This lets widgets that only handle mouse input work on touchscreens.
+    /// Raw touch events are still delivered, and movement is only emulated
+    /// once the finger travels past a small drag threshold. Emulated events
+    /// are only seen by widgets; subscriptions keep receiving the raw touch
+    /// events alone.
+    ///
+    /// By default, it is disabled.
+    pub mouse_emulation: bool,
+
+    /// The maximum interval between two clicks for them to be considered a
+    /// double click (or a triple click, for the next one).
+    ///
+    /// It should normally match the interval configured by the user in their
+    /// desktop environment.
+    ///
+    /// By default, it is `None`, which uses an interval of 300 milliseconds.
+    pub double_click_interval: Option<Duration>,
+
+    /// The [`MessageLog`] that receives the [`Debug`] representation of
+    /// every message processed by the runtime.
+    ///
+    /// This is useful to build developer tools, like a live message log.
+    /// Since it is not a [`Subscription`], logging never produces new
+    /// messages on its own.
+    ///
+    /// By default, it is not set.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    /// [`Subscription`]: crate::Subscription
+    #[cfg(feature = "debug")]
+    pub message_log: Option<MessageLog>,
 }
 
 impl Default for Settings {
@@ -45,6 +85,10 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            mouse_emulation: false,
+            double_click_interval: None,
+            #[cfg(feature = "debug")]
+            message_log: None,
         }
     }
 }
@@ -54,6 +98,10 @@ impl From<Settings> for iced_winit::Settings {
         iced_winit::Settings {
             id: settings.id,
             fonts: settings.fonts,
+            mouse_emulation: settings.mouse_emulation,
+            double_click_interval: settings.double_click_interval,
+            #[cfg(feature = "debug")]
+            message_log: settings.message_log,
         }
     }
 }
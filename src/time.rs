@@ -12,3 +12,34 @@ pub use crate::core::time::{Duration, Instant};
     )))
 )]
 pub use iced_futures::backend::default::time::*;
+
+/// Creates a [`Task`](crate::Task) that produces the given value once the
+/// `duration` has passed.
+///
+/// This is handy for one-shot timers (e.g. dismissing a notification) that do
+/// not warrant a [`time::every`](every) subscription.
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol",
+        target_arch = "wasm32"
+    )))
+)]
+pub fn delay<T>(duration: Duration, value: T) -> crate::Task<T>
+where
+    T: iced_futures::MaybeSend + 'static,
+{
+    crate::Task::future(async move {
+        sleep(duration).await;
+
+        value
+    })
+}
@@ -1818,6 +1818,20 @@ pub fn focus_next<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_next()))
 }
 
+/// Focuses the previous focusable widget with the given
+/// [`Edge`](operation::focusable::Edge) behavior.
+pub fn focus_previous_with<T>(edge: operation::focusable::Edge) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_previous_with(
+        edge,
+    )))
+}
+
+/// Focuses the next focusable widget with the given
+/// [`Edge`](operation::focusable::Edge) behavior.
+pub fn focus_next_with<T>(edge: operation::focusable::Edge) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus_next_with(edge)))
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -101,6 +101,16 @@ pub fn window_attributes(
 
     #[cfg(target_os = "linux")]
     {
+        // Fall back to the identifier of the application when no
+        // explicit application id has been set for the window.
+        #[cfg(any(feature = "x11", feature = "wayland"))]
+        let application_id =
+            if settings.platform_specific.application_id.is_empty() {
+                _id.unwrap_or_default()
+            } else {
+                settings.platform_specific.application_id
+            };
+
         #[cfg(feature = "x11")]
         {
             use winit::platform::x11::WindowAttributesExtX11;
@@ -109,19 +119,13 @@ pub fn window_attributes(
                 .with_override_redirect(
                     settings.platform_specific.override_redirect,
                 )
-                .with_name(
-                    &settings.platform_specific.application_id,
-                    &settings.platform_specific.application_id,
-                );
+                .with_name(&application_id, &application_id);
         }
         #[cfg(feature = "wayland")]
         {
             use winit::platform::wayland::WindowAttributesExtWayland;
 
-            attributes = attributes.with_name(
-                &settings.platform_specific.application_id,
-                &settings.platform_specific.application_id,
-            );
+            attributes = attributes.with_name(&application_id, &application_id);
         }
     }
 
@@ -1135,6 +1139,20 @@ pub fn icon(icon: window::Icon) -> Option<winit::window::Icon> {
     winit::window::Icon::from_rgba(pixels, size.width, size.height).ok()
 }
 
+/// Converts a [`mouse::CustomCursor`] into a `winit` custom cursor source.
+pub fn custom_cursor(
+    cursor: mouse::CustomCursor,
+) -> Option<winit::window::CustomCursorSource> {
+    winit::window::CustomCursor::from_rgba(
+        cursor.rgba,
+        cursor.size.width,
+        cursor.size.height,
+        cursor.hotspot.x,
+        cursor.hotspot.y,
+    )
+    .ok()
+}
+
 // See: https://en.wikipedia.org/wiki/Private_Use_Areas
 fn is_private_use(c: char) -> bool {
     ('\u{E000}'..='\u{F8FF}').contains(&c)
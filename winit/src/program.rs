@@ -1,4 +1,5 @@
 //! Create interactive, native cross-platform applications for WGPU.
+mod emulation;
 mod state;
 mod window_manager;
 
@@ -136,6 +137,18 @@ where
     fn scale_factor(&self, window: window::Id) -> f64 {
         1.0
     }
+
+    /// Produces a message once the `window` of the [`Program`] has been
+    /// opened, given its initial logical [`Size`].
+    ///
+    /// The [`Size`] is the one reported by the windowing system after the
+    /// window has been created, not the requested one.
+    ///
+    /// By default, it returns `None`.
+    #[allow(unused_variables)]
+    fn opened(&self, window: window::Id, size: Size) -> Option<Self::Message> {
+        None
+    }
 }
 
 /// The appearance of a program.
@@ -187,6 +200,15 @@ where
     let mut debug = Debug::new();
     debug.startup_started();
 
+    if let Some(interval) = settings.double_click_interval {
+        mouse::click::set_interval(interval);
+    }
+
+    #[cfg(feature = "debug")]
+    if let Some(message_log) = settings.message_log.clone() {
+        debug.log_messages_with(move |message| message_log.log(message));
+    }
+
     let event_loop = EventLoop::with_user_event()
         .build()
         .expect("Create event loop");
@@ -235,6 +257,7 @@ where
         event_receiver,
         control_sender,
         is_daemon,
+        settings.mouse_emulation,
     ));
 
     let context = task::Context::from_waker(task::noop_waker_ref());
@@ -513,6 +536,8 @@ where
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
 
+                                let tag = settings.tag.clone();
+
                                 let visible = settings.visible;
 
                                 #[cfg(target_arch = "wasm32")]
@@ -594,11 +619,17 @@ where
                                         id,
                                         window,
                                         exit_on_close_request,
+                                        tag,
                                         make_visible: visible,
                                         on_open,
                                     },
                                 );
                             }
+                            Control::ChangeCustomCursor { window, source } => {
+                                window.set_cursor(
+                                    event_loop.create_custom_cursor(source),
+                                );
+                            }
                             Control::Exit => {
                                 event_loop.exit();
                             }
@@ -643,6 +674,7 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: winit::window::Window,
         exit_on_close_request: bool,
+        tag: Option<String>,
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
     },
@@ -660,6 +692,10 @@ enum Control {
         monitor: Option<winit::monitor::MonitorHandle>,
         on_open: oneshot::Sender<window::Id>,
     },
+    ChangeCustomCursor {
+        window: Arc<winit::window::Window>,
+        source: winit::window::CustomCursorSource,
+    },
 }
 
 async fn run_instance<P, C>(
@@ -671,6 +707,7 @@ async fn run_instance<P, C>(
     mut event_receiver: mpsc::UnboundedReceiver<Event<Action<P::Message>>>,
     mut control_sender: mpsc::UnboundedSender<Control>,
     is_daemon: bool,
+    mouse_emulation: bool,
 ) where
     P: Program + 'static,
     C: Compositor<Renderer = P::Renderer> + 'static,
@@ -711,6 +748,7 @@ async fn run_instance<P, C>(
                 id,
                 window,
                 exit_on_close_request,
+                tag,
                 make_visible,
                 on_open,
             } => {
@@ -720,6 +758,8 @@ async fn run_instance<P, C>(
                     &program,
                     &mut compositor,
                     exit_on_close_request,
+                    tag,
+                    mouse_emulation,
                 );
 
                 let logical_size = window.state.logical_size();
@@ -749,6 +789,10 @@ async fn run_instance<P, C>(
                     }),
                 ));
 
+                if let Some(message) = program.opened(id, window.size()) {
+                    messages.push(message);
+                }
+
                 if clipboard.window_id().is_none() {
                     clipboard = Clipboard::connect(window.raw.clone());
                 }
@@ -801,12 +845,21 @@ async fn run_instance<P, C>(
                         event: event::WindowEvent::RedrawRequested,
                         ..
                     } => {
+                        let cursor_hide_deadline = window_manager
+                            .iter_mut()
+                            .filter_map(|(_id, window)| {
+                                window.hide_idle_cursor()
+                            })
+                            .min();
+
                         let Some((id, window)) =
                             window_manager.get_mut_alias(id)
                         else {
                             continue;
                         };
 
+                        debug.frame_requested();
+
                         // TODO: Avoid redrawing all the time by forcing widgets to
                         // request redraws on state changes
                         //
@@ -842,11 +895,13 @@ async fn run_instance<P, C>(
                         debug.draw_finished();
 
                         if new_mouse_interaction != window.mouse_interaction {
-                            window.raw.set_cursor(
-                                conversion::mouse_interaction(
-                                    new_mouse_interaction,
-                                ),
-                            );
+                            if !window.has_custom_cursor {
+                                window.raw.set_cursor(
+                                    conversion::mouse_interaction(
+                                        new_mouse_interaction,
+                                    ),
+                                );
+                            }
 
                             window.mouse_interaction = new_mouse_interaction;
                         }
@@ -857,28 +912,45 @@ async fn run_instance<P, C>(
                             status: core::event::Status::Ignored,
                         });
 
-                        let _ = control_sender.start_send(Control::ChangeFlow(
-                            match ui_state {
-                                user_interface::State::Updated {
-                                    redraw_request: Some(redraw_request),
-                                } => match redraw_request {
-                                    window::RedrawRequest::NextFrame => {
-                                        window.raw.request_redraw();
+                        let control_flow = match ui_state {
+                            user_interface::State::Updated {
+                                redraw_request: Some(redraw_request),
+                            } => match redraw_request {
+                                window::RedrawRequest::NextFrame => {
+                                    window.raw.request_redraw();
 
-                                        ControlFlow::Wait
-                                    }
-                                    window::RedrawRequest::At(at) => {
-                                        ControlFlow::WaitUntil(at)
-                                    }
-                                },
-                                _ => ControlFlow::Wait,
+                                    ControlFlow::Wait
+                                }
+                                window::RedrawRequest::At(at) => {
+                                    ControlFlow::WaitUntil(at)
+                                }
                             },
-                        ));
+                            _ => ControlFlow::Wait,
+                        };
+
+                        // The event loop wakes up at the earliest of the redraw
+                        // request and the cursor hide deadline of any window
+                        let control_flow =
+                            match (control_flow, cursor_hide_deadline) {
+                                (
+                                    ControlFlow::WaitUntil(at),
+                                    Some(deadline),
+                                ) => ControlFlow::WaitUntil(at.min(deadline)),
+                                (ControlFlow::Wait, Some(deadline)) => {
+                                    ControlFlow::WaitUntil(deadline)
+                                }
+                                (control_flow, _) => control_flow,
+                            };
+
+                        let _ = control_sender
+                            .start_send(Control::ChangeFlow(control_flow));
 
                         let physical_size = window.state.physical_size();
 
                         if physical_size.width == 0 || physical_size.height == 0
                         {
+                            debug.frame_skipped();
+
                             continue;
                         }
 
@@ -914,11 +986,13 @@ async fn run_instance<P, C>(
 
                             if new_mouse_interaction != window.mouse_interaction
                             {
-                                window.raw.set_cursor(
-                                    conversion::mouse_interaction(
-                                        new_mouse_interaction,
-                                    ),
-                                );
+                                if !window.has_custom_cursor {
+                                    window.raw.set_cursor(
+                                        conversion::mouse_interaction(
+                                            new_mouse_interaction,
+                                        ),
+                                    );
+                                }
 
                                 window.mouse_interaction =
                                     new_mouse_interaction;
@@ -944,6 +1018,9 @@ async fn run_instance<P, C>(
                         ) {
                             Ok(()) => {
                                 debug.render_finished();
+                                debug.frame_presented();
+
+                                window.present_failures = 0;
                             }
                             Err(error) => match error {
                                 // This is an unrecoverable error.
@@ -952,12 +1029,55 @@ async fn run_instance<P, C>(
                                 }
                                 _ => {
                                     debug.render_finished();
+                                    debug.frame_skipped();
 
                                     log::error!(
                                         "Error {error:?} when \
                                         presenting surface."
                                     );
 
+                                    // Timeouts and outdated surfaces are
+                                    // transient, but a surface that keeps
+                                    // getting lost has to be recreated.
+                                    //
+                                    // Only the surface is recreated; a lost
+                                    // device (e.g. after a GPU reset) is not
+                                    // recovered from.
+                                    if matches!(
+                                        error,
+                                        compositor::SurfaceError::Lost
+                                    ) {
+                                        window.present_failures += 1;
+                                    }
+
+                                    if window.present_failures
+                                        >= MAX_PRESENT_FAILURES
+                                    {
+                                        log::warn!(
+                                            "Recreating surface of \
+                                            window {id:?}"
+                                        );
+
+                                        // The old surface must be dropped
+                                        // before the new one is configured,
+                                        // since its swapchain still owns the
+                                        // native window.
+                                        window.surface = compositor
+                                            .create_surface(
+                                                window.raw.clone(),
+                                                0,
+                                                0,
+                                            );
+
+                                        compositor.configure_surface(
+                                            &mut window.surface,
+                                            physical_size.width,
+                                            physical_size.height,
+                                        );
+
+                                        window.present_failures = 0;
+                                    }
+
                                     // Try rendering all windows again next frame.
                                     for (_id, window) in
                                         window_manager.iter_mut()
@@ -1015,6 +1135,13 @@ async fn run_instance<P, C>(
                                 &mut is_window_opening,
                             );
                         } else {
+                            if matches!(
+                                window_event,
+                                winit::event::WindowEvent::CursorMoved { .. }
+                            ) {
+                                window.reveal_cursor();
+                            }
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
@@ -1054,16 +1181,48 @@ async fn run_instance<P, C>(
                                 continue;
                             }
 
-                            let (ui_state, statuses) = user_interfaces
+                            let ui = user_interfaces
                                 .get_mut(&id)
-                                .expect("Get user interface")
-                                .update(
-                                    &window_events,
-                                    window.state.cursor(),
-                                    &mut window.renderer,
-                                    &mut clipboard,
-                                    &mut messages,
-                                );
+                                .expect("Get user interface");
+
+                            let (mut ui_state, statuses) = ui.update(
+                                &window_events,
+                                window.state.cursor(),
+                                &mut window.renderer,
+                                &mut clipboard,
+                                &mut messages,
+                            );
+
+                            if let Some(emulation) = &mut window.mouse_emulation
+                            {
+                                let emulated_events: Vec<_> = window_events
+                                    .iter()
+                                    .zip(&statuses)
+                                    .flat_map(|(event, status)| {
+                                        emulation.emulate(event, *status)
+                                    })
+                                    .collect();
+
+                                if !emulated_events.is_empty() {
+                                    // Emulated events are only meant for
+                                    // widgets; subscriptions keep receiving
+                                    // the raw touch events alone.
+                                    let (emulated_state, _) = ui.update(
+                                        &emulated_events,
+                                        window.state.cursor(),
+                                        &mut window.renderer,
+                                        &mut clipboard,
+                                        &mut messages,
+                                    );
+
+                                    if matches!(
+                                        emulated_state,
+                                        user_interface::State::Outdated
+                                    ) {
+                                        ui_state = emulated_state;
+                                    }
+                                }
+                            }
 
                             window.raw.request_redraw();
 
@@ -1149,6 +1308,10 @@ async fn run_instance<P, C>(
     let _ = ManuallyDrop::into_inner(user_interfaces);
 }
 
+/// The amount of consecutive presents of a window failing with a lost
+/// surface after which its surface is recreated.
+const MAX_PRESENT_FAILURES: u32 = 3;
+
 /// Builds a window's [`UserInterface`] for the [`Program`].
 fn build_user_interface<'a, P: Program>(
     program: &'a P,
@@ -1280,6 +1443,19 @@ fn run_action<P, C>(
 
                 let _ = channel.send(id);
             }
+            window::Action::GetAll(channel) => {
+                let windows = window_manager
+                    .iter_mut()
+                    .map(|(id, window)| core::window::Summary {
+                        id,
+                        title: window.state.title().to_owned(),
+                        size: window.size(),
+                        is_focused: window.raw.has_focus(),
+                    })
+                    .collect();
+
+                let _ = channel.send(windows);
+            }
             window::Action::Drag(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let _ = window.raw.drag_window();
@@ -1348,6 +1524,16 @@ fn run_action<P, C>(
                     let _ = channel.send(scale_factor as f32);
                 }
             }
+            window::Action::GetCursorPosition(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let _ = channel.send(window.state.cursor().position());
+                }
+            }
+            window::Action::GetInputState(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let _ = channel.send(window.state.input_state());
+                }
+            }
             window::Action::Move(id, position) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_outer_position(
@@ -1358,6 +1544,56 @@ fn run_action<P, C>(
                     );
                 }
             }
+            window::Action::AutoHideCursor(id, after) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.cursor_auto_hide = after;
+                    window.reveal_cursor();
+                    window.raw.request_redraw();
+                }
+            }
+            window::Action::ChangeCustomCursor(id, cursor) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    match cursor.map(conversion::custom_cursor) {
+                        Some(Some(source)) => {
+                            window.has_custom_cursor = true;
+
+                            control_sender
+                                .start_send(Control::ChangeCustomCursor {
+                                    window: window.raw.clone(),
+                                    source,
+                                })
+                                .expect("Send control action");
+                        }
+                        Some(None) => {
+                            log::warn!("Invalid custom cursor for {id:?}");
+                        }
+                        None => {
+                            window.has_custom_cursor = false;
+                            window.raw.set_cursor(
+                                conversion::mouse_interaction(
+                                    window.mouse_interaction,
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+            window::Action::Center(id) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let size = window
+                        .raw
+                        .outer_size()
+                        .to_logical(window.raw.scale_factor());
+
+                    if let Some(position) = conversion::position(
+                        window.raw.current_monitor().as_ref(),
+                        Size::new(size.width, size.height),
+                        crate::core::window::Position::Centered,
+                    ) {
+                        window.raw.set_outer_position(position);
+                    }
+                }
+            }
             window::Action::ChangeMode(id, mode) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     window.raw.set_visible(conversion::visible(mode));
@@ -1372,6 +1608,12 @@ fn run_action<P, C>(
                     window.raw.set_window_icon(conversion::icon(icon));
                 }
             }
+            window::Action::ChangeClearColor(id, color) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.state.set_clear_color(color);
+                    window.raw.request_redraw();
+                }
+            }
             window::Action::GetMode(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let mode = if window.raw.is_visible().unwrap_or(true) {
@@ -1426,6 +1668,11 @@ fn run_action<P, C>(
                     }
                 }
             }
+            window::Action::GetTag(id, channel) => {
+                if let Some(window) = window_manager.get(id) {
+                    let _ = channel.send(window.tag.clone());
+                }
+            }
             window::Action::GetRawId(id, channel) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let _ = channel.send(window.raw.id().into());
@@ -1483,6 +1730,12 @@ fn run_action<P, C>(
                     });
                 }
             }
+            system::Action::QueryFonts(_channel) => {
+                #[cfg(feature = "system")]
+                {
+                    let _ = _channel.send(crate::system::font_families());
+                }
+            }
         },
         Action::Widget(operation) => {
             let mut current_operation = Some(operation);
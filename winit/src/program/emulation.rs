@@ -0,0 +1,246 @@
+use crate::core::event;
+use crate::core::mouse;
+use crate::core::touch;
+use crate::core::{Event, Point};
+
+/// The distance a finger needs to travel before its movement starts being
+/// emulated as mouse movement.
+const DRAG_THRESHOLD: f32 = 8.0;
+
+/// Synthesizes mouse events out of single-finger touch interactions.
+#[derive(Debug, Default)]
+pub struct MouseEmulation {
+    finger: Option<Finger>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Finger {
+    id: touch::Finger,
+    origin: Point,
+    is_dragging: bool,
+}
+
+impl MouseEmulation {
+    /// Produces the mouse events emulating the given touch event.
+    ///
+    /// An interaction is only emulated if the finger that started it was
+    /// ignored by the user interface; this way, widgets that already handle
+    /// touch do not receive the same interaction twice.
+    pub fn emulate(
+        &mut self,
+        event: &Event,
+        status: event::Status,
+    ) -> Vec<Event> {
+        let Event::Touch(touch_event) = event else {
+            return Vec::new();
+        };
+
+        match *touch_event {
+            touch::Event::FingerPressed { id, position } => {
+                if self.finger.is_some() || status == event::Status::Captured {
+                    return Vec::new();
+                }
+
+                self.finger = Some(Finger {
+                    id,
+                    origin: position,
+                    is_dragging: false,
+                });
+
+                vec![
+                    Event::Mouse(mouse::Event::CursorMoved { position }),
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    )),
+                ]
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let Some(finger) = self.finger.as_mut() else {
+                    return Vec::new();
+                };
+
+                if finger.id != id {
+                    return Vec::new();
+                }
+
+                if !finger.is_dragging
+                    && finger.origin.distance(position) < DRAG_THRESHOLD
+                {
+                    return Vec::new();
+                }
+
+                finger.is_dragging = true;
+
+                vec![Event::Mouse(mouse::Event::CursorMoved { position })]
+            }
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. } => {
+                if self.finger.map(|finger| finger.id) != Some(id) {
+                    return Vec::new();
+                }
+
+                self.finger = None;
+
+                vec![Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finger(id: u64) -> touch::Finger {
+        touch::Finger(id)
+    }
+
+    fn pressed(id: u64, position: Point) -> Event {
+        Event::Touch(touch::Event::FingerPressed {
+            id: finger(id),
+            position,
+        })
+    }
+
+    fn moved(id: u64, position: Point) -> Event {
+        Event::Touch(touch::Event::FingerMoved {
+            id: finger(id),
+            position,
+        })
+    }
+
+    fn lifted(id: u64, position: Point) -> Event {
+        Event::Touch(touch::Event::FingerLifted {
+            id: finger(id),
+            position,
+        })
+    }
+
+    fn lost(id: u64, position: Point) -> Event {
+        Event::Touch(touch::Event::FingerLost {
+            id: finger(id),
+            position,
+        })
+    }
+
+    fn released() -> Vec<Event> {
+        vec![Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        ))]
+    }
+
+    #[test]
+    fn ignored_press_is_emulated() {
+        let mut emulation = MouseEmulation::default();
+        let position = Point::new(10.0, 10.0);
+
+        assert_eq!(
+            emulation.emulate(&pressed(0, position), event::Status::Ignored),
+            vec![
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ]
+        );
+    }
+
+    #[test]
+    fn captured_press_is_not_emulated() {
+        let mut emulation = MouseEmulation::default();
+        let position = Point::new(10.0, 10.0);
+
+        assert!(emulation
+            .emulate(&pressed(0, position), event::Status::Captured)
+            .is_empty());
+
+        assert!(emulation
+            .emulate(&lifted(0, position), event::Status::Ignored)
+            .is_empty());
+    }
+
+    #[test]
+    fn moves_under_drag_threshold_are_suppressed() {
+        let mut emulation = MouseEmulation::default();
+        let origin = Point::new(10.0, 10.0);
+
+        let _ = emulation.emulate(&pressed(0, origin), event::Status::Ignored);
+
+        assert!(emulation
+            .emulate(
+                &moved(0, Point::new(10.0 + DRAG_THRESHOLD / 2.0, 10.0)),
+                event::Status::Ignored
+            )
+            .is_empty());
+
+        let position = Point::new(10.0 + DRAG_THRESHOLD * 2.0, 10.0);
+
+        assert_eq!(
+            emulation.emulate(&moved(0, position), event::Status::Ignored),
+            vec![Event::Mouse(mouse::Event::CursorMoved { position })]
+        );
+
+        // Once dragging, small movements are emulated too.
+        let position = Point::new(10.0 + DRAG_THRESHOLD * 2.0 + 1.0, 10.0);
+
+        assert_eq!(
+            emulation.emulate(&moved(0, position), event::Status::Ignored),
+            vec![Event::Mouse(mouse::Event::CursorMoved { position })]
+        );
+    }
+
+    #[test]
+    fn second_finger_is_ignored() {
+        let mut emulation = MouseEmulation::default();
+        let position = Point::new(10.0, 10.0);
+        let far = Point::new(100.0, 100.0);
+
+        let _ =
+            emulation.emulate(&pressed(0, position), event::Status::Ignored);
+
+        assert!(emulation
+            .emulate(&pressed(1, far), event::Status::Ignored)
+            .is_empty());
+        assert!(emulation
+            .emulate(
+                &moved(1, Point::new(200.0, 200.0)),
+                event::Status::Ignored
+            )
+            .is_empty());
+        assert!(emulation
+            .emulate(&lifted(1, far), event::Status::Ignored)
+            .is_empty());
+
+        assert_eq!(
+            emulation.emulate(&lifted(0, position), event::Status::Ignored),
+            released()
+        );
+    }
+
+    #[test]
+    fn lifted_or_lost_finger_is_released() {
+        let mut emulation = MouseEmulation::default();
+        let position = Point::new(10.0, 10.0);
+
+        let _ =
+            emulation.emulate(&pressed(0, position), event::Status::Ignored);
+
+        assert_eq!(
+            emulation.emulate(&lifted(0, position), event::Status::Ignored),
+            released()
+        );
+
+        let _ =
+            emulation.emulate(&pressed(1, position), event::Status::Ignored);
+
+        assert_eq!(
+            emulation.emulate(&lost(1, position), event::Status::Ignored),
+            released()
+        );
+
+        // A release is only produced once per interaction.
+        assert!(emulation
+            .emulate(&lost(1, position), event::Status::Ignored)
+            .is_empty());
+    }
+}
@@ -19,8 +19,10 @@ where
     viewport_version: u64,
     cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
     modifiers: winit::keyboard::ModifiersState,
+    pressed_buttons: Vec<mouse::Button>,
     theme: P::Theme,
     appearance: program::Appearance,
+    clear_color: Option<Color>,
 }
 
 impl<P: Program> Debug for State<P>
@@ -35,6 +37,7 @@ where
             .field("viewport_version", &self.viewport_version)
             .field("cursor_position", &self.cursor_position)
             .field("appearance", &self.appearance)
+            .field("clear_color", &self.clear_color)
             .finish()
     }
 }
@@ -70,11 +73,18 @@ where
             viewport_version: 0,
             cursor_position: None,
             modifiers: winit::keyboard::ModifiersState::default(),
+            pressed_buttons: Vec::new(),
             theme,
             appearance,
+            clear_color: None,
         }
     }
 
+    /// Returns the current title of the [`State`].
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
     /// Returns the current [`Viewport`] of the [`State`].
     pub fn viewport(&self) -> &Viewport {
         &self.viewport
@@ -120,14 +130,32 @@ where
         self.modifiers
     }
 
+    /// Returns the current [`window::InputState`] of the [`State`].
+    pub fn input_state(&self) -> window::InputState {
+        window::InputState {
+            modifiers: conversion::modifiers(self.modifiers),
+            buttons: self.pressed_buttons.clone(),
+        }
+    }
+
     /// Returns the current theme of the [`State`].
     pub fn theme(&self) -> &P::Theme {
         &self.theme
     }
 
     /// Returns the current background [`Color`] of the [`State`].
+    ///
+    /// This is the clear color set with [`State::set_clear_color`], if any,
+    /// or the background color of the current theme otherwise.
     pub fn background_color(&self) -> Color {
-        self.appearance.background_color
+        self.clear_color.unwrap_or(self.appearance.background_color)
+    }
+
+    /// Overrides the background [`Color`] of the [`State`].
+    ///
+    /// Passing `None` restores the background color of the current theme.
+    pub fn set_clear_color(&mut self, clear_color: Option<Color>) {
+        self.clear_color = clear_color;
     }
 
     /// Returns the current text [`Color`] of the [`State`].
@@ -178,6 +206,18 @@ where
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = new_modifiers.state();
             }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let button = conversion::mouse_button(*button);
+
+                self.pressed_buttons.retain(|pressed| *pressed != button);
+
+                if *state == winit::event::ElementState::Pressed {
+                    self.pressed_buttons.push(button);
+                }
+            }
+            WindowEvent::Focused(false) => {
+                self.pressed_buttons.clear();
+            }
             #[cfg(feature = "debug")]
             WindowEvent::KeyboardInput {
                 event:
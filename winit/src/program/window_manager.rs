@@ -1,7 +1,9 @@
 use crate::core::mouse;
+use crate::core::time::{Duration, Instant};
 use crate::core::window::Id;
 use crate::core::{Point, Size};
 use crate::graphics::Compositor;
+use crate::program::emulation::MouseEmulation;
 use crate::program::{DefaultStyle, Program, State};
 
 use std::collections::BTreeMap;
@@ -39,6 +41,8 @@ where
         application: &P,
         compositor: &mut C,
         exit_on_close_request: bool,
+        tag: Option<String>,
+        mouse_emulation: bool,
     ) -> &mut Window<P, C> {
         let state = State::new(application, id, &window);
         let viewport_version = state.viewport_version();
@@ -59,9 +63,16 @@ where
                 state,
                 viewport_version,
                 exit_on_close_request,
+                tag,
+                mouse_emulation: mouse_emulation.then(MouseEmulation::default),
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                has_custom_cursor: false,
+                cursor_auto_hide: None,
+                last_cursor_movement: Instant::now(),
+                is_cursor_hidden: false,
+                present_failures: 0,
             },
         );
 
@@ -135,7 +146,14 @@ where
     pub state: State<P>,
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
+    pub tag: Option<String>,
+    pub mouse_emulation: Option<MouseEmulation>,
     pub mouse_interaction: mouse::Interaction,
+    pub has_custom_cursor: bool,
+    pub cursor_auto_hide: Option<Duration>,
+    pub last_cursor_movement: Instant,
+    pub is_cursor_hidden: bool,
+    pub present_failures: u32,
     pub surface: C::Surface,
     pub renderer: P::Renderer,
 }
@@ -162,4 +180,38 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    /// Restarts the idle timer of the cursor and shows it if it was hidden.
+    pub fn reveal_cursor(&mut self) {
+        self.last_cursor_movement = Instant::now();
+
+        if self.is_cursor_hidden {
+            self.raw.set_cursor_visible(true);
+            self.is_cursor_hidden = false;
+        }
+    }
+
+    /// Hides the cursor if it has been idle for longer than the auto-hide
+    /// timeout.
+    ///
+    /// Returns the [`Instant`] at which the cursor should be hidden, if it
+    /// is still pending.
+    pub fn hide_idle_cursor(&mut self) -> Option<Instant> {
+        let timeout = self.cursor_auto_hide?;
+
+        if self.is_cursor_hidden {
+            return None;
+        }
+
+        let deadline = self.last_cursor_movement + timeout;
+
+        if Instant::now() >= deadline {
+            self.raw.set_cursor_visible(false);
+            self.is_cursor_hidden = true;
+
+            None
+        } else {
+            Some(deadline)
+        }
+    }
 }
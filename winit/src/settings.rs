@@ -1,5 +1,11 @@
 //! Configure your application.
+use crate::core::time::Duration;
+
 use std::borrow::Cow;
+#[cfg(feature = "debug")]
+use std::fmt;
+#[cfg(feature = "debug")]
+use std::sync::Arc;
 
 /// The settings of an application.
 #[derive(Debug, Clone, Default)]
@@ -12,4 +18,53 @@ pub struct Settings {
 
     /// The fonts to load on boot.
     pub fonts: Vec<Cow<'static, [u8]>>,
+
+    /// Whether single-finger touch interactions should be emulated as
+    /// mouse input for widgets that ignore touch events.
+    ///
+    /// Emulated events are not broadcast to subscriptions.
+    pub mouse_emulation: bool,
+
+    /// The maximum interval between two clicks for them to be considered a
+    /// double click, if different from the default.
+    pub double_click_interval: Option<Duration>,
+
+    /// The [`MessageLog`] that receives every message processed by the
+    /// runtime, if any.
+    #[cfg(feature = "debug")]
+    pub message_log: Option<MessageLog>,
+}
+
+/// A function that receives the [`Debug`] representation of every message
+/// processed by the runtime.
+///
+/// It is called in the event loop right before each message is handed to
+/// `update`, so it should return quickly (e.g. by pushing into a buffer
+/// shared with an in-app inspector). Messages are already formatted for the
+/// debug overlay when the `debug` feature is enabled, so the only extra
+/// overhead is the call itself.
+///
+/// [`Debug`]: std::fmt::Debug
+#[cfg(feature = "debug")]
+#[derive(Clone)]
+pub struct MessageLog(Arc<dyn Fn(&str) + Send + Sync>);
+
+#[cfg(feature = "debug")]
+impl MessageLog {
+    /// Creates a new [`MessageLog`] that calls the given function.
+    pub fn new(f: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Logs the given message.
+    pub fn log(&self, message: &str) {
+        (self.0)(message);
+    }
+}
+
+#[cfg(feature = "debug")]
+impl fmt::Debug for MessageLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MessageLog(..)")
+    }
 }
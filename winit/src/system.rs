@@ -1,5 +1,6 @@
 //! Access the native system.
 use crate::graphics::compositor;
+use crate::graphics::text;
 use crate::runtime::system::{Action, Information};
 use crate::runtime::{self, Task};
 
@@ -10,6 +11,14 @@ pub fn fetch_information() -> Task<Information> {
     })
 }
 
+/// Query for the names of the font families known to the renderer,
+/// including any fonts loaded during the session.
+pub fn fetch_fonts() -> Task<Vec<String>> {
+    runtime::task::oneshot(|channel| {
+        runtime::Action::System(Action::QueryFonts(channel))
+    })
+}
+
 pub(crate) fn information(
     graphics_info: compositor::Information,
 ) -> Information {
@@ -35,5 +44,33 @@ pub(crate) fn information(
         memory_used,
         graphics_adapter: graphics_info.adapter,
         graphics_backend: graphics_info.backend,
+        desktop_environment: desktop_environment(),
     }
 }
+
+fn desktop_environment() -> Option<String> {
+    [
+        "XDG_CURRENT_DESKTOP",
+        "XDG_SESSION_DESKTOP",
+        "DESKTOP_SESSION",
+    ]
+    .into_iter()
+    .filter_map(|variable| std::env::var(variable).ok())
+    .find(|name| !name.is_empty())
+}
+
+pub(crate) fn font_families() -> Vec<String> {
+    use std::collections::BTreeSet;
+
+    let mut font_system =
+        text::font_system().write().expect("Write font system");
+
+    let families: BTreeSet<String> = font_system
+        .raw()
+        .db()
+        .faces()
+        .flat_map(|face| face.families.iter().map(|(name, _)| name.clone()))
+        .collect();
+
+    families.into_iter().collect()
+}